@@ -7,6 +7,15 @@ CURRENTWD=$PWD
 FIND_FILE="/etc/sudoers"
 FIND_STR="short_press_gpio420"
 
+#镜像源与代理设置，可用环境变量覆盖默认值
+APT_MIRROR=${ONEKVM_APT_MIRROR:-mirrors.tuna.tsinghua.edu.cn}
+PIP_INDEX=${ONEKVM_PIP_INDEX:-https://pypi.tuna.tsinghua.edu.cn/simple/}
+if [ -n "$ONEKVM_PROXY" ]; then
+  export http_proxy=$ONEKVM_PROXY
+  export https_proxy=$ONEKVM_PROXY
+  echo "已启用代理：$ONEKVM_PROXY"
+fi
+
 #检查架构和Python版本
 check-environment(){
   echo -e "\e[0;32m设备名称：$MACHINE\nPython版本：$PYVER"
@@ -62,7 +71,7 @@ override-uboot(){
 
 #安装依赖软件
 install-dependencies(){
-  bash <(curl -sSL https://gitee.com/SuperManito/LinuxMirrors/raw/main/ChangeMirrors.sh) --source mirrors.tuna.tsinghua.edu.cn --updata-software false --web-protocol http && echo "换源成功！"
+  bash <(curl -sSL https://gitee.com/SuperManito/LinuxMirrors/raw/main/ChangeMirrors.sh) --source $APT_MIRROR --updata-software false --web-protocol http && echo "换源成功！当前软件源：$APT_MIRROR"
   echo -e "\e[0;32m正在安装依赖软件nginx tesseract-ocr tesseract-ocr-eng janus libevent-dev libgpiod-dev tesseract-ocr-chi-sim......"  
   apt install -y nginx tesseract-ocr tesseract-ocr-eng janus libevent-dev libgpiod-dev tesseract-ocr-chi-sim  >> ./log.txt
 }
@@ -102,15 +111,19 @@ add-patches(){
   patch -s -p0 < chinese.patch
   echo  -e "\e[0;32m中文补丁应用成功！"
   apt install -y libjpeg-dev libfreetype6-dev python3-dev python3-pip
-  pip3 config set global.index-url https://pypi.tuna.tsinghua.edu.cn/simple/
+  pip3 config set global.index-url $PIP_INDEX
   pip3 install -U Pillow
 
 }
 
 show-info(){
   echo "One-KVM V0.5" >> installed.txt 
-  ipaddr=`ip addr | grep "scope global" | awk '{print $2}' |awk -F/ '{print $1}'`
+  ipaddr=`ip -4 addr | grep "scope global" | awk '{print $2}' |awk -F/ '{print $1}'`
+  ip6addr=`ip -6 addr | grep "scope global" | awk '{print $2}' |awk -F/ '{print $1}'`
   echo  -e "\e[0;32m内网访问地址为：\nhttp://$ipaddr\nhttps://$ipaddr"
+  if [ -n "$ip6addr" ]; then
+    echo  -e "IPv6访问地址为：\nhttp://[$ip6addr]\nhttps://[$ip6addr]"
+  fi
   echo "机器已重启，等待10秒然后拔插电源，One-KVM就安装完成了！"
 }
 